@@ -5,24 +5,188 @@ fn greet(name: &str) -> String {
 }
 
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::io::{BufRead, BufReader};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream};
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::{Duration, Instant};
-use tauri::{Manager, State, WindowEvent};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager, State, WindowEvent};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
+
+// How many backend log lines we keep around so a window that opens late (or reloads)
+// can still pull recent history instead of only seeing lines emitted from now on.
+const BACKEND_LOG_HISTORY: usize = 500;
+
+// Name of the bundled, PyInstaller-frozen backend binary as declared under
+// `tauri.conf.json`'s `bundle.externalBin` (Tauri resolves the per-target suffix).
+const BACKEND_SIDECAR_NAME: &str = "agent-studio-backend";
+
+// Shared by both launch paths on Windows so `request_graceful_exit`'s CTRL_BREAK has a
+// process group to target instead of silently failing (see `spawn_command_process` and
+// `spawn_sidecar_process`).
+#[cfg(windows)]
+const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BackendMode {
+    /// Shell out to `python -m uvicorn ...` (or `AGENT_STUDIO_BACKEND_CMD`). Requires a
+    /// system Python / venv on PATH — fine for dev, broken in packaged builds.
+    Command,
+    /// Launch the bundled backend binary via Tauri's sidecar mechanism. No Python install
+    /// required; this is what packaged builds should use.
+    Sidecar,
+}
+
+impl BackendMode {
+    fn from_env() -> Self {
+        match std::env::var("AGENT_STUDIO_BACKEND_MODE").as_deref() {
+            Ok("sidecar") => BackendMode::Sidecar,
+            _ => BackendMode::Command,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            BackendMode::Command => "command",
+            BackendMode::Sidecar => "sidecar",
+        }
+    }
+}
+
+// Liveness/exit-code for a sidecar child, updated by the task draining its event stream
+// (see `spawn_sidecar_process`) since `CommandChild` exposes no `try_wait`.
+struct SidecarHandle {
+    child: CommandChild,
+    running: Arc<Mutex<bool>>,
+    exit_code: Arc<Mutex<Option<i32>>>,
+}
+
+enum BackendProcess {
+    Command(Child),
+    Sidecar(SidecarHandle),
+}
+
+impl BackendProcess {
+    fn id(&self) -> u32 {
+        match self {
+            BackendProcess::Command(c) => c.id(),
+            BackendProcess::Sidecar(s) => s.child.pid(),
+        }
+    }
+
+    fn mode(&self) -> BackendMode {
+        match self {
+            BackendProcess::Command(_) => BackendMode::Command,
+            BackendProcess::Sidecar(_) => BackendMode::Sidecar,
+        }
+    }
+
+    fn is_running(&mut self) -> bool {
+        match self {
+            BackendProcess::Command(c) => child_is_running(c),
+            BackendProcess::Sidecar(s) => s.running.lock().map(|g| *g).unwrap_or(false),
+        }
+    }
+
+    fn exit_code(&mut self) -> Option<i32> {
+        match self {
+            BackendProcess::Command(c) => c.try_wait().ok().flatten().and_then(|s| s.code()),
+            BackendProcess::Sidecar(s) => s.exit_code.lock().ok().and_then(|g| *g),
+        }
+    }
+
+    fn kill(&mut self) {
+        match self {
+            BackendProcess::Command(c) => {
+                let _ = c.kill();
+                let _ = c.wait();
+            }
+            BackendProcess::Sidecar(s) => {
+                let _ = s.child.kill();
+            }
+        }
+    }
+}
 
 #[derive(Default)]
 struct BackendState {
-    child: Mutex<Option<Child>>,
+    child: Mutex<Option<BackendProcess>>,
+    logs: Arc<Mutex<VecDeque<LogLine>>>,
+    // Cleared by `backend_stop` so a deliberate stop isn't fought by the supervisor.
+    should_supervise: Mutex<bool>,
+    restart_count: Mutex<u32>,
+    last_exit_code: Mutex<Option<i32>>,
+    // Set when the supervisor owes the backend a restart (a crash, or a respawn attempt
+    // that itself failed) and cleared once a replacement is actually running. Tracked
+    // separately from `child` because a failed respawn also leaves `child` empty, which
+    // would otherwise look identical to "nothing has crashed" and never get retried.
+    needs_restart: Mutex<bool>,
+    // Set once the window's close handler has kicked off the background graceful stop, so
+    // the `CloseRequested` fired by our own follow-up `window.close()` doesn't loop back in.
+    shutting_down: Mutex<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct BackendStatus {
     url: String,
     running: bool,
+    ready: bool,
     pid: Option<u32>,
+    // False when `pid` belongs to a process we didn't spawn (a stale uvicorn, or an
+    // unrelated service squatting on the port) — the UI can warn and offer to reclaim it.
+    owned_by_us: bool,
+    // Which launch path produced the running process ("command" or "sidecar"); `None` when
+    // we can't attribute it to us (not running, or `owned_by_us` is false).
+    mode: Option<&'static str>,
+    restart_count: u32,
+    last_exit_code: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackendRestarted {
+    attempt: u32,
+    exit_code: Option<i32>,
+}
+
+// Exponential backoff schedule for supervisor restarts: 0.5s, 1s, 2s, 4s, then doubling
+// (8s, 16s, ...) up to a cap of 30s.
+const SUPERVISOR_BACKOFF_MS: &[u64] = &[500, 1000, 2000, 4000];
+const SUPERVISOR_BACKOFF_CAP_MS: u64 = 30_000;
+const SUPERVISOR_POLL_MS: u64 = 500;
+const SUPERVISOR_STABLE_AFTER_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LogLine {
+    stream: &'static str,
+    line: String,
+    ts: u64,
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn record_log_line(app: &AppHandle, logs: &Mutex<VecDeque<LogLine>>, stream: &'static str, line: String) {
+    let entry = LogLine {
+        stream,
+        line,
+        ts: unix_millis(),
+    };
+
+    if let Ok(mut buf) = logs.lock() {
+        buf.push_back(entry.clone());
+        while buf.len() > BACKEND_LOG_HISTORY {
+            buf.pop_front();
+        }
+    }
+
+    let _ = app.emit("backend-log", entry);
 }
 
 fn backend_url() -> String {
@@ -38,6 +202,97 @@ fn tcp_is_listening(addr: SocketAddr, timeout: Duration) -> bool {
     TcpStream::connect_timeout(&addr, timeout).is_ok()
 }
 
+// Resolves the PID bound to `addr`, if any, so we can tell our own backend apart from a
+// foreign process (a stale uvicorn, or an unrelated service) squatting on the same port.
+fn resolve_port_owner(addr: SocketAddr) -> Option<u32> {
+    use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
+
+    let sockets = get_sockets_info(AddressFamilyFlags::IPV4, ProtocolFlags::TCP).ok()?;
+
+    sockets.into_iter().find_map(|si| match &si.protocol_socket_info {
+        ProtocolSocketInfo::Tcp(tcp) if tcp.local_port == addr.port() && tcp.local_addr == addr.ip() => {
+            si.associated_pids.first().copied()
+        }
+        _ => None,
+    })
+}
+
+// On Windows, `spawn_command_process` runs the backend through `cmd /C`, so the PID we
+// track is the `cmd.exe` wrapper, not the `python.exe` grandchild that actually binds the
+// port — the two will never be literally equal even though the backend is ours. Walk the
+// process tree from the socket's owner up to (bounded) root looking for our tracked PID
+// before falling back to treating the pair as unrelated.
+#[cfg(windows)]
+fn pid_is_ancestor_of(ancestor: u32, descendant: u32) -> bool {
+    use sysinfo::{Pid, ProcessesToUpdate, System};
+
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut current = Pid::from_u32(descendant);
+    for _ in 0..16 {
+        if current.as_u32() == ancestor {
+            return true;
+        }
+        match system.process(current).and_then(|p| p.parent()) {
+            Some(parent) => current = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+#[cfg(not(windows))]
+fn pid_is_ancestor_of(_ancestor: u32, _descendant: u32) -> bool {
+    false
+}
+
+// Combines our tracked child (if still alive) with a port-ownership probe so callers get a
+// consistent (running, pid, owned_by_us, mode) view regardless of whether we spawned the
+// backend or merely found it already listening.
+fn inspect_backend(addr: SocketAddr, state: &BackendState) -> (bool, Option<u32>, bool, Option<&'static str>) {
+    let (tracked_pid, tracked_mode) = {
+        let mut child_guard = match state.child.lock() {
+            Ok(guard) => guard,
+            Err(_) => return (false, None, false, None),
+        };
+        match child_guard.as_mut() {
+            Some(process) if process.is_running() => (Some(process.id()), Some(process.mode().as_str())),
+            _ => (None, None),
+        }
+    };
+
+    let port_listening = tcp_is_listening(addr, Duration::from_millis(150));
+    let port_pid = if port_listening { resolve_port_owner(addr) } else { None };
+
+    let running = tracked_pid.is_some() || port_listening;
+    let pid = port_pid.or(tracked_pid);
+    let owned_by_us = match (tracked_pid, port_pid) {
+        (Some(tracked), Some(owner)) => tracked == owner || pid_is_ancestor_of(tracked, owner),
+        (Some(_), None) => true,
+        _ => false,
+    };
+    let mode = if owned_by_us { tracked_mode } else { None };
+
+    (running, pid, owned_by_us, mode)
+}
+
+fn health_url() -> String {
+    let path = std::env::var("AGENT_STUDIO_HEALTH_PATH").unwrap_or_else(|_| "/health".into());
+    format!("{}{}", backend_url(), path)
+}
+
+// uvicorn accepts TCP connections before the ASGI app has finished importing, so a bare
+// socket check can report `running: true` while every request still 500s. Only a 2xx
+// response from the health endpoint means the app is actually serving.
+fn http_is_ready(url: &str, timeout: Duration) -> bool {
+    let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+    match agent.get(url).call() {
+        Ok(resp) => (200..300).contains(&resp.status()),
+        Err(_) => false,
+    }
+}
+
 fn child_is_running(child: &mut Child) -> bool {
     match child.try_wait() {
         Ok(Some(_status)) => false,
@@ -46,7 +301,69 @@ fn child_is_running(child: &mut Child) -> bool {
     }
 }
 
-fn spawn_backend_process() -> Result<Child, String> {
+fn supervision_snapshot(state: &BackendState) -> (u32, Option<i32>) {
+    let restart_count = state.restart_count.lock().map(|g| *g).unwrap_or(0);
+    let last_exit_code = state.last_exit_code.lock().map(|g| *g).unwrap_or(None);
+    (restart_count, last_exit_code)
+}
+
+fn stop_grace_period() -> Duration {
+    let ms = std::env::var("AGENT_STUDIO_STOP_GRACE_MS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(3000);
+    Duration::from_millis(ms)
+}
+
+#[cfg(unix)]
+fn request_graceful_exit(pid: u32) {
+    // SAFETY: `kill` only signals the PID we were handed by the process we spawned; no
+    // pointers involved.
+    unsafe {
+        libc::kill(pid as libc::pid_t, libc::SIGTERM);
+    }
+}
+
+#[cfg(windows)]
+fn request_graceful_exit(pid: u32) {
+    // SAFETY: FFI call with a plain PID; the child was spawned into its own process group
+    // (see `spawn_command_process`) so this doesn't also signal us.
+    unsafe {
+        windows_sys::Win32::System::Console::GenerateConsoleCtrlEvent(
+            windows_sys::Win32::System::Console::CTRL_BREAK_EVENT,
+            pid,
+        );
+    }
+}
+
+// uvicorn only runs its shutdown hooks and closes in-flight requests cleanly if it's asked to
+// exit rather than killed outright. Ask nicely first (SIGTERM / CTRL_BREAK), give it
+// `AGENT_STUDIO_STOP_GRACE_MS` (default 3s) to comply, then fall back to a hard kill.
+fn graceful_stop_process(process: &mut BackendProcess) {
+    request_graceful_exit(process.id());
+
+    let deadline = Instant::now() + stop_grace_period();
+    while Instant::now() < deadline {
+        if !process.is_running() {
+            return;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    process.kill();
+}
+
+// Picks the launch path via `AGENT_STUDIO_BACKEND_MODE` (`command` by default, `sidecar` to
+// use the bundled PyInstaller binary). Dev environments generally want `command`; packaged
+// builds, which have no system Python on PATH, want `sidecar`.
+fn spawn_backend_process(app: &AppHandle, logs: &Arc<Mutex<VecDeque<LogLine>>>) -> Result<BackendProcess, String> {
+    match BackendMode::from_env() {
+        BackendMode::Command => spawn_command_process(app, logs).map(BackendProcess::Command),
+        BackendMode::Sidecar => spawn_sidecar_process(app, logs).map(BackendProcess::Sidecar),
+    }
+}
+
+fn spawn_command_process(app: &AppHandle, logs: &Arc<Mutex<VecDeque<LogLine>>>) -> Result<Child, String> {
     // Prefer an explicit command supplied by the host environment.
     // This makes dev + packaging flexible without hardcoding venv paths.
     //
@@ -66,8 +383,13 @@ fn spawn_backend_process() -> Result<Child, String> {
 
     #[cfg(target_os = "windows")]
     let mut c = {
+        // Its own process group so we can target it with CTRL_BREAK later without also
+        // signalling ourselves (see `request_graceful_exit`).
+        use std::os::windows::process::CommandExt;
+
         let mut c = Command::new("cmd");
         c.args(["/C", &cmd]);
+        c.creation_flags(CREATE_NEW_PROCESS_GROUP);
         c
     };
 
@@ -92,24 +414,30 @@ fn spawn_backend_process() -> Result<Child, String> {
     println!("[agent-studio] spawned backend (pid={}): {}", child.id(), cmd);
 
     if let Some(stdout) = child.stdout.take() {
+        let app = app.clone();
+        let logs = logs.clone();
         thread::Builder::new()
             .name("agent_studio_backend_stdout".into())
             .spawn(move || {
                 let reader = BufReader::new(stdout);
                 for line in reader.lines().flatten() {
                     println!("[backend stdout] {line}");
+                    record_log_line(&app, &logs, "stdout", line);
                 }
             })
             .ok();
     }
 
     if let Some(stderr) = child.stderr.take() {
+        let app = app.clone();
+        let logs = logs.clone();
         thread::Builder::new()
             .name("agent_studio_backend_stderr".into())
             .spawn(move || {
                 let reader = BufReader::new(stderr);
                 for line in reader.lines().flatten() {
                     eprintln!("[backend stderr] {line}");
+                    record_log_line(&app, &logs, "stderr", line);
                 }
             })
             .ok();
@@ -118,33 +446,125 @@ fn spawn_backend_process() -> Result<Child, String> {
     Ok(child)
 }
 
+// Runs the bundled, PyInstaller-frozen backend binary through Tauri's sidecar mechanism
+// instead of shelling out to `python`, so packaged builds don't depend on a system
+// interpreter. Stdout/stderr piping and the readiness check are unchanged either way.
+fn spawn_sidecar_process(
+    app: &AppHandle,
+    logs: &Arc<Mutex<VecDeque<LogLine>>>,
+) -> Result<SidecarHandle, String> {
+    let allow_origins = std::env::var("AGENT_STUDIO_ALLOW_CORS_ORIGINS").unwrap_or_else(|_| "*".into());
+
+    let sidecar = app
+        .shell()
+        .sidecar(BACKEND_SIDECAR_NAME)
+        .map_err(|e| format!("failed_to_resolve_backend_sidecar: {e}"))?
+        .env("AGENT_STUDIO_ALLOW_CORS_ORIGINS", allow_origins)
+        .env("PYTHONUNBUFFERED", "1");
+
+    // Its own process group, same as `spawn_command_process`, so `request_graceful_exit`'s
+    // CTRL_BREAK has a valid process group to target instead of silently failing against a
+    // PID that isn't a group leader (which made graceful shutdown always time out and fall
+    // through to a hard kill for sidecar builds on Windows).
+    #[cfg(windows)]
+    let sidecar = sidecar.creation_flags(CREATE_NEW_PROCESS_GROUP);
+
+    let (mut rx, child) = sidecar
+        .spawn()
+        .map_err(|e| format!("failed_to_spawn_backend_sidecar: {e}"))?;
+
+    println!("[agent-studio] spawned backend sidecar (pid={})", child.pid());
+
+    let running = Arc::new(Mutex::new(true));
+    let exit_code = Arc::new(Mutex::new(None));
+
+    let app = app.clone();
+    let logs = logs.clone();
+    let running_for_task = running.clone();
+    let exit_code_for_task = exit_code.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(bytes) => {
+                    let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                    println!("[backend stdout] {line}");
+                    record_log_line(&app, &logs, "stdout", line);
+                }
+                CommandEvent::Stderr(bytes) => {
+                    let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                    eprintln!("[backend stderr] {line}");
+                    record_log_line(&app, &logs, "stderr", line);
+                }
+                CommandEvent::Error(err) => {
+                    eprintln!("[agent-studio] backend sidecar error: {err}");
+                }
+                CommandEvent::Terminated(payload) => {
+                    if let Ok(mut running) = running_for_task.lock() {
+                        *running = false;
+                    }
+                    if let Ok(mut exit_code) = exit_code_for_task.lock() {
+                        *exit_code = payload.code;
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
+    Ok(SidecarHandle {
+        child,
+        running,
+        exit_code,
+    })
+}
+
+#[tauri::command]
+fn backend_logs(state: State<'_, BackendState>) -> Result<Vec<LogLine>, String> {
+    let buf = state.logs.lock().map_err(|_| "backend_state_poisoned".to_string())?;
+    Ok(buf.iter().cloned().collect())
+}
+
 #[tauri::command]
 fn backend_status(state: State<'_, BackendState>) -> Result<BackendStatus, String> {
     let url = backend_url();
-    let mut child_guard = state.child.lock().map_err(|_| "backend_state_poisoned".to_string())?;
-
-    let (running, pid) = if let Some(child) = child_guard.as_mut() {
-        let running = child_is_running(child);
-        let pid = if running { Some(child.id()) } else { None };
-        (running, pid)
-    } else {
-        (false, None)
-    };
+    let (running, pid, owned_by_us, mode) = inspect_backend(backend_listen_addr(), &state);
+    let ready = running && http_is_ready(&health_url(), Duration::from_millis(300));
+    let (restart_count, last_exit_code) = supervision_snapshot(&state);
 
-    Ok(BackendStatus { url, running, pid })
+    Ok(BackendStatus {
+        url,
+        running,
+        ready,
+        pid,
+        owned_by_us,
+        mode,
+        restart_count,
+        last_exit_code,
+    })
 }
 
 #[tauri::command]
-fn backend_start(state: State<'_, BackendState>) -> Result<BackendStatus, String> {
+fn backend_start(app: AppHandle, state: State<'_, BackendState>) -> Result<BackendStatus, String> {
     let url = backend_url();
     let addr = backend_listen_addr();
+    let health = health_url();
 
-    // If something is already listening, treat it as “running”.
+    // If something is already listening, treat it as “running” but only report it as
+    // `ready` once the health endpoint actually answers. It may not even be ours.
     if tcp_is_listening(addr, Duration::from_millis(150)) {
+        let ready = http_is_ready(&health, Duration::from_millis(500));
+        let (_, pid, owned_by_us, mode) = inspect_backend(addr, &state);
+        let (restart_count, last_exit_code) = supervision_snapshot(&state);
         return Ok(BackendStatus {
             url,
             running: true,
-            pid: None,
+            ready,
+            pid,
+            owned_by_us,
+            mode,
+            restart_count,
+            last_exit_code,
         });
     }
 
@@ -152,66 +572,245 @@ fn backend_start(state: State<'_, BackendState>) -> Result<BackendStatus, String
     {
         let mut child_guard =
             state.child.lock().map_err(|_| "backend_state_poisoned".to_string())?;
-        if let Some(child) = child_guard.as_mut() {
-            if !child_is_running(child) {
+        if let Some(process) = child_guard.as_mut() {
+            if !process.is_running() {
                 *child_guard = None;
             }
         }
     }
 
-    // Spawn backend process.
-    let child = spawn_backend_process()?;
+    // Spawn backend process. Mark it as ours to supervise before we hand control back,
+    // so a crash during the readiness wait is still caught by the supervisor thread.
+    let process = spawn_backend_process(&app, &state.logs)?;
+    let mode = process.mode().as_str();
     {
         let mut child_guard =
             state.child.lock().map_err(|_| "backend_state_poisoned".to_string())?;
-        *child_guard = Some(child);
+        *child_guard = Some(process);
     }
+    *state.should_supervise.lock().map_err(|_| "backend_state_poisoned".to_string())? = true;
 
-    // Wait for port to come up.
+    // Wait for the backend to actually serve requests, not just accept the socket.
     let deadline = Instant::now() + Duration::from_secs(6);
     while Instant::now() < deadline {
-        if tcp_is_listening(addr, Duration::from_millis(150)) {
+        if http_is_ready(&health, Duration::from_millis(300)) {
             let pid = state
                 .child
                 .lock()
                 .ok()
-                .and_then(|mut g| g.as_mut().map(|c| c.id()));
+                .and_then(|mut g| g.as_mut().map(|p| p.id()));
+            let (restart_count, last_exit_code) = supervision_snapshot(&state);
             return Ok(BackendStatus {
                 url,
                 running: true,
+                ready: true,
                 pid,
+                owned_by_us: true,
+                mode: Some(mode),
+                restart_count,
+                last_exit_code,
             });
         }
         thread::sleep(Duration::from_millis(150));
     }
 
-    Err("backend_start_timeout_waiting_for_port".into())
+    Err("backend_start_timeout_waiting_for_health".into())
 }
 
 #[tauri::command]
 fn backend_stop(state: State<'_, BackendState>) -> Result<BackendStatus, String> {
     let url = backend_url();
-    let mut child_guard = state.child.lock().map_err(|_| "backend_state_poisoned".to_string())?;
 
-    if let Some(mut child) = child_guard.take() {
-        // Best-effort terminate; ignore errors (process may have already exited).
-        let _ = child.kill();
-        let _ = child.wait();
+    // Clear this first so the supervisor thread doesn't race us and respawn the child
+    // we're about to kill.
+    *state.should_supervise.lock().map_err(|_| "backend_state_poisoned".to_string())? = false;
+    *state.needs_restart.lock().map_err(|_| "backend_state_poisoned".to_string())? = false;
+
+    // Take the process out of its own sub-scope so the lock is released before the
+    // (potentially multi-second) grace period, instead of being held for the whole call and
+    // blocking every other command that touches `state.child`.
+    let process = { state.child.lock().map_err(|_| "backend_state_poisoned".to_string())?.take() };
+
+    if let Some(mut process) = process {
+        graceful_stop_process(&mut process);
     }
 
+    let (restart_count, last_exit_code) = supervision_snapshot(&state);
+
     Ok(BackendStatus {
         url,
         running: false,
+        ready: false,
         pid: None,
+        owned_by_us: false,
+        mode: None,
+        restart_count,
+        last_exit_code,
     })
 }
 
+// Watches the tracked child and restarts it with exponential backoff if it exits while
+// `should_supervise` is set. A manual `backend_stop` clears that flag first, so it never
+// fights a deliberate shutdown.
+fn spawn_supervisor(app: AppHandle) {
+    thread::Builder::new()
+        .name("agent_studio_backend_supervisor".into())
+        .spawn(move || loop {
+            thread::sleep(Duration::from_millis(SUPERVISOR_POLL_MS));
+
+            let state = app.state::<BackendState>();
+            let supervising = state.should_supervise.lock().map(|g| *g).unwrap_or(false);
+            if !supervising {
+                continue;
+            }
+
+            // A restart can already be pending from an earlier crash whose respawn attempt
+            // itself failed — `child` is `None` either way, so without this flag that looks
+            // identical to "nothing has crashed" and we'd never retry (see `needs_restart`).
+            let already_pending = state.needs_restart.lock().map(|g| *g).unwrap_or(false);
+
+            if !already_pending {
+                let crashed_exit_code = {
+                    let mut child_guard = match state.child.lock() {
+                        Ok(guard) => guard,
+                        Err(_) => continue,
+                    };
+                    let crashed = match child_guard.as_mut() {
+                        Some(process) => !process.is_running(),
+                        None => false,
+                    };
+                    if !crashed {
+                        continue;
+                    }
+                    let code = child_guard.as_mut().and_then(|p| p.exit_code());
+                    *child_guard = None;
+                    code
+                };
+
+                if let Ok(mut last_exit_code) = state.last_exit_code.lock() {
+                    *last_exit_code = crashed_exit_code;
+                }
+                if let Ok(mut pending) = state.needs_restart.lock() {
+                    *pending = true;
+                }
+            }
+
+            let exit_code = state.last_exit_code.lock().map(|g| *g).unwrap_or(None);
+
+            let attempt = {
+                let mut count = match state.restart_count.lock() {
+                    Ok(count) => count,
+                    Err(_) => continue,
+                };
+                *count += 1;
+                *count
+            };
+
+            let delay_ms = SUPERVISOR_BACKOFF_MS
+                .get((attempt as usize).saturating_sub(1))
+                .copied()
+                .unwrap_or_else(|| {
+                    // Past the table: keep doubling from its last entry until the cap.
+                    let last = SUPERVISOR_BACKOFF_MS[SUPERVISOR_BACKOFF_MS.len() - 1];
+                    let extra = (attempt as usize - SUPERVISOR_BACKOFF_MS.len()).min(16) as u32;
+                    last.saturating_mul(1u64 << extra)
+                })
+                .min(SUPERVISOR_BACKOFF_CAP_MS);
+
+            eprintln!(
+                "[agent-studio] backend down (exit_code={:?}), restarting in {}ms (attempt {})",
+                exit_code, delay_ms, attempt
+            );
+            thread::sleep(Duration::from_millis(delay_ms));
+
+            // `backend_stop` may have run while we were backing off.
+            if !state.should_supervise.lock().map(|g| *g).unwrap_or(false) {
+                continue;
+            }
+
+            // A manual `backend_start` may have already
+            // installed a live replacement while we were sleeping through the backoff; don't
+            // clobber the tracked handle with a second spawn and orphan it.
+            {
+                let mut child_guard = match state.child.lock() {
+                    Ok(guard) => guard,
+                    Err(_) => continue,
+                };
+                let already_replaced = child_guard.as_mut().map(|p| p.is_running()).unwrap_or(false);
+                if already_replaced {
+                    eprintln!(
+                        "[agent-studio] backend already replaced during backoff, skipping restart (attempt {})",
+                        attempt
+                    );
+                    if let Ok(mut pending) = state.needs_restart.lock() {
+                        *pending = false;
+                    }
+                    continue;
+                }
+            }
+
+            match spawn_backend_process(&app, &state.logs) {
+                Ok(process) => {
+                    if let Ok(mut pending) = state.needs_restart.lock() {
+                        *pending = false;
+                    }
+
+                    // Captured so the stability watcher below can confirm it's still
+                    // watching *this* restart rather than some later child that happens to
+                    // be up at the 60s mark.
+                    let spawned_pid = process.id();
+                    if let Ok(mut guard) = state.child.lock() {
+                        *guard = Some(process);
+                    }
+                    let _ = app.emit("backend-restarted", BackendRestarted { attempt, exit_code });
+
+                    // Reset the backoff counter once this specific restart proves stable.
+                    let stability_app = app.clone();
+                    thread::Builder::new()
+                        .name("agent_studio_backend_stability_watch".into())
+                        .spawn(move || {
+                            thread::sleep(Duration::from_secs(SUPERVISOR_STABLE_AFTER_SECS));
+                            let state = stability_app.state::<BackendState>();
+                            let still_same_and_running = state
+                                .child
+                                .lock()
+                                .ok()
+                                .map(|mut g| {
+                                    g.as_mut()
+                                        .map(|p| p.id() == spawned_pid && p.is_running())
+                                        .unwrap_or(false)
+                                })
+                                .unwrap_or(false);
+                            if still_same_and_running {
+                                if let Ok(mut count) = state.restart_count.lock() {
+                                    *count = 0;
+                                }
+                            }
+                        })
+                        .ok();
+                }
+                Err(e) => {
+                    // Leave `needs_restart` set: `child` is still empty, and without the
+                    // flag that's indistinguishable from "nothing crashed", so the next poll
+                    // tick retries with an escalated backoff instead of giving up silently.
+                    eprintln!(
+                        "[agent-studio] supervisor failed to restart backend: {e}, will retry at the next backoff step"
+                    );
+                }
+            }
+        })
+        .ok();
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .manage(BackendState::default())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_shell::init())
         .setup(|app| {
+            spawn_supervisor(app.handle().clone());
+
             // Optional autostart so the UI can assume the Python backend is available.
             // Enable with: AGENT_STUDIO_AUTOSTART_BACKEND=1
             let autostart = std::env::var("AGENT_STUDIO_AUTOSTART_BACKEND")
@@ -222,9 +821,14 @@ pub fn run() {
             if autostart {
                 let addr = backend_listen_addr();
                 if !tcp_is_listening(addr, Duration::from_millis(150)) {
-                    if let Ok(child) = spawn_backend_process() {
+                    let handle = app.handle().clone();
+                    let logs = app.state::<BackendState>().logs.clone();
+                    if let Ok(process) = spawn_backend_process(&handle, &logs) {
                         if let Ok(mut guard) = app.state::<BackendState>().child.lock() {
-                            *guard = Some(child);
+                            *guard = Some(process);
+                        }
+                        if let Ok(mut guard) = app.state::<BackendState>().should_supervise.lock() {
+                            *guard = true;
                         }
                     }
                 }
@@ -235,19 +839,54 @@ pub fn run() {
             greet,
             backend_start,
             backend_stop,
-            backend_status
+            backend_status,
+            backend_logs
         ])
         .on_window_event(|window, event| {
-            if let WindowEvent::CloseRequested { .. } = event {
-                // Ensure we don't orphan the Python backend when the app closes.
-                if let Some(state) = window.app_handle().try_state::<BackendState>() {
-                    if let Ok(mut child_guard) = state.child.lock() {
-                        if let Some(mut child) = child_guard.take() {
-                            let _ = child.kill();
-                            let _ = child.wait();
-                        }
-                    }
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                // Ensure we don't orphan the Python backend when the app closes. The grace
+                // period (`AGENT_STUDIO_STOP_GRACE_MS`, default 3s) is too long to eat
+                // synchronously on the event-loop thread, so defer the actual close: run the
+                // graceful stop on a background thread and close the window ourselves once
+                // it's done.
+                let Some(state) = window.app_handle().try_state::<BackendState>() else {
+                    return;
+                };
+
+                let already_shutting_down = {
+                    let mut flag = match state.shutting_down.lock() {
+                        Ok(flag) => flag,
+                        Err(_) => return,
+                    };
+                    std::mem::replace(&mut *flag, true)
+                };
+                if already_shutting_down {
+                    // This is the `CloseRequested` our own `window.close()` below triggers;
+                    // let it proceed instead of preventing it again.
+                    return;
                 }
+
+                api.prevent_close();
+
+                let window = window.clone();
+                thread::Builder::new()
+                    .name("agent_studio_shutdown".into())
+                    .spawn(move || {
+                        if let Some(state) = window.app_handle().try_state::<BackendState>() {
+                            if let Ok(mut guard) = state.should_supervise.lock() {
+                                *guard = false;
+                            }
+                            // Same sub-scope trick as `backend_stop`: release the lock before
+                            // the grace period so a concurrent `backend_status` isn't blocked
+                            // on it while we wait.
+                            let process = state.child.lock().ok().and_then(|mut g| g.take());
+                            if let Some(mut process) = process {
+                                graceful_stop_process(&mut process);
+                            }
+                        }
+                        let _ = window.close();
+                    })
+                    .ok();
             }
         })
         .run(tauri::generate_context!())